@@ -1,42 +1,133 @@
 
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ptr::{addr_of, addr_of_mut};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+pub mod queue;
+pub mod priority_queue;
+
+pub use queue::Queue;
+pub use priority_queue::ConstPriorityQueue;
+
 #[derive(Debug, PartialEq)]
 pub enum QueueErr
 {
     QueueFull,
     Empty,
-    UnknownError
 }
 
+impl core::fmt::Display for QueueErr
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        let msg = match self
+        {
+            QueueErr::QueueFull => "queue is full",
+            QueueErr::Empty => "queue is empty",
+        };
+        write!(f, "{}", msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for QueueErr {}
+
 pub struct ConstQueue<Ty, const SIZE: usize>
 {
-    buff: [Option<Ty>; SIZE],
-    start: usize,
-    end: usize,
+    // `UnsafeCell` so that after `split` the producer and consumer can write
+    // their respective slots through raw pointers without ever forming a
+    // reference to the whole queue (which would alias across threads).
+    buff: UnsafeCell<[MaybeUninit<Ty>; SIZE]>,
+    start: AtomicUsize,
+    end: AtomicUsize,
+    len: AtomicUsize,
 }
 
 impl <Ty, const SIZE: usize> ConstQueue<Ty, SIZE>
 {
     pub fn new() -> Self
     {
-        unsafe {
-            Self
-            {
-                buff: core::mem::zeroed(),
-                start: 0,
-                end: 0
-            }
+        Self
+        {
+            // `assume_init()` here only asserts that the *array of
+            // MaybeUninit<Ty>* is initialized, not the `Ty`s inside it, so
+            // this holds no matter what bit pattern `Ty` considers valid;
+            // occupancy is tracked separately via `len`, not by inspecting
+            // the slots.
+            buff: UnsafeCell::new(unsafe { MaybeUninit::uninit().assume_init() }),
+            start: AtomicUsize::new(0),
+            end: AtomicUsize::new(0),
+            len: AtomicUsize::new(0),
         }
     }
 
     /// Yields true, if the queue is empty
     pub fn empty(&self) -> bool
     {
-        let first = self.peek();
-        if let Err(e) = first
+        return self.is_empty();
+    }
+
+    /// Yields true, if the queue is empty
+    pub fn is_empty(&self) -> bool
+    {
+        return self.len.load(Ordering::Acquire) == 0;
+    }
+
+    /// Returns the number of items currently in the queue.
+    pub fn len(&self) -> usize
+    {
+        return self.len.load(Ordering::Acquire);
+    }
+
+    /// Yields true when the queue holds SIZE items and no further push will
+    /// succeed.
+    pub fn is_full(&self) -> bool
+    {
+        return self.len.load(Ordering::Acquire) == SIZE;
+    }
+
+    /// Returns the fixed capacity of the queue.
+    pub fn capacity(&self) -> usize
+    {
+        return SIZE;
+    }
+
+    /// Returns an iterator yielding a shared reference to each queued element
+    /// from oldest to newest, without consuming the queue. Unlike the
+    /// consuming [`Iterator`] impl this places no `Copy` requirement on `Ty`.
+    pub fn iter(&self) -> Iter<'_, Ty, SIZE>
+    {
+        Iter
         {
-            return e == QueueErr::Empty;
+            buff: unsafe { &*self.buff.get() },
+            idx: self.start.load(Ordering::Relaxed),
+            remaining: self.len.load(Ordering::Acquire),
         }
-        return false;
+    }
+
+    /// Returns an iterator yielding a mutable reference to each queued element
+    /// from oldest to newest, letting callers edit elements in place.
+    pub fn iter_mut(&mut self) -> IterMut<'_, Ty, SIZE>
+    {
+        let idx = self.start.load(Ordering::Relaxed);
+        let remaining = self.len.load(Ordering::Acquire);
+        IterMut
+        {
+            buff: self.buff.get_mut().as_mut_ptr(),
+            idx,
+            remaining,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a draining iterator that removes and yields owned elements from
+    /// oldest to newest. Any elements left when the [`Drain`] is dropped are
+    /// removed too, leaving the queue empty.
+    pub fn drain(&mut self) -> Drain<'_, Ty, SIZE>
+    {
+        Drain { queue: self }
     }
 
     /// Tries to push a given item into the queue
@@ -46,14 +137,15 @@ impl <Ty, const SIZE: usize> ConstQueue<Ty, SIZE>
     /// Will Result Err(QueueErr::Full) if the queue is full
     pub fn push(&mut self, item: Ty) -> Result<(), QueueErr>
     {
-        let next_end = (self.end + 1) % SIZE;
-        if next_end == self.start
+        if self.len.load(Ordering::Relaxed) == SIZE
         {
             return Err(QueueErr::QueueFull);
         }
 
-        self.buff[self.end] = Some(item);
-        self.end = next_end;
+        let end = self.end.load(Ordering::Relaxed);
+        self.buff.get_mut()[end] = MaybeUninit::new(item);
+        self.end.store((end + 1) % SIZE, Ordering::Release);
+        self.len.fetch_add(1, Ordering::Release);
         Ok(())
     }
 
@@ -70,50 +162,344 @@ impl <Ty, const SIZE: usize> ConstQueue<Ty, SIZE>
         }
     }
 
+    /// Pushes a given item into the queue, overwriting the oldest element when
+    /// the queue is full. This turns the queue into a fixed-capacity
+    /// most-recent-N history buffer, as wanted by logging/telemetry callers
+    /// where the newest data matters most.
+    /// # Arguments
+    /// * `item`: The item to push
+    ///
+    /// Returns `Some(old)` with the dropped oldest element if the queue was
+    /// full, or `None` if there was spare capacity. A zero-capacity queue
+    /// stores nothing and returns `Some(item)` with the item passed in.
+    pub fn overwrite_push(&mut self, item: Ty) -> Option<Ty>
+    {
+        if SIZE == 0
+        {
+            // No slot exists to hold `item` or to have evicted anything from.
+            return Some(item);
+        }
+
+        let evicted = if self.len.load(Ordering::Relaxed) == SIZE
+        {
+            /* Full: take the oldest element out and advance start past it. */
+            let start = self.start.load(Ordering::Relaxed);
+            let old = unsafe { self.buff.get_mut()[start].assume_init_read() };
+            self.start.store((start + 1) % SIZE, Ordering::Release);
+            self.len.fetch_sub(1, Ordering::Release);
+            Some(old)
+        }
+        else
+        {
+            None
+        };
+
+        let end = self.end.load(Ordering::Relaxed);
+        self.buff.get_mut()[end] = MaybeUninit::new(item);
+        self.end.store((end + 1) % SIZE, Ordering::Release);
+        self.len.fetch_add(1, Ordering::Release);
+        evicted
+    }
+
     /// Returns an immutable reference to the first element
     /// of the queue or Err(QueueErr::Empty), if no
     /// element is in the queue
     pub fn peek(&self) -> Result<&Ty, QueueErr>
     {
-        if self.start < self.end
-        {
-            let v = &self.buff[self.start];
-            match v
-            {
-                Some(ref x) => return Ok(x),
-                _ => return Err(QueueErr::UnknownError) /* This should never happen */
-            }
-        }
-        if self.start > self.end
+        if self.len.load(Ordering::Acquire) == 0
         {
-            let v = &self.buff[self.start];
-            match v
-            {
-                Some(ref x) => return Ok(x),
-                _ => return Err(QueueErr::UnknownError) /* This should never happen */
-            }
+            return Err(QueueErr::Empty);
         }
-        return Err(QueueErr::Empty);
+
+        let start = self.start.load(Ordering::Relaxed);
+        return Ok(unsafe { (*self.buff.get())[start].assume_init_ref() });
     }
 
     /// Removes the first item in the queue and returns it.
     /// Will return Err(QeueErr::Empty) if no item is in the queue
     pub fn pop(&mut self) -> Result<Ty, QueueErr>
     {
-        if self.start == self.end
+        if self.len.load(Ordering::Acquire) == 0
         {
             return Err(QueueErr::Empty)
         }
 
-        let next_start = (self.start + 1) % SIZE;
-        let result =  Ok(self.buff[self.start].take().unwrap());
-        self.start = next_start;
-        return result;
+        let start = self.start.load(Ordering::Relaxed);
+        let result = unsafe { self.buff.get_mut()[start].assume_init_read() };
+        self.start.store((start + 1) % SIZE, Ordering::Release);
+        self.len.fetch_sub(1, Ordering::Release);
+        return Ok(result);
+    }
+
+    /// Splits the queue into a [`Producer`]/[`Consumer`] pair that borrow the
+    /// queue for the lifetime of the split. The producer is meant to live on
+    /// the thread that enqueues and the consumer on the thread that dequeues:
+    /// the producer exclusively mutates `end` and the consumer exclusively
+    /// mutates `start`, so the two sides never contend on the same atomic.
+    /// Fullness/emptiness is tested by each side reading the *other* side's
+    /// index with `Acquire`, and each side publishes its own advance with
+    /// `Release`; this is the same single-writer-per-index split `heapless`
+    /// uses for its `spsc::Queue`, and as there it costs one slot of
+    /// capacity (the queue is full when `end + 1 == start`, not when
+    /// `len == SIZE`), so a split queue can hold at most `SIZE - 1` items.
+    pub fn split(&mut self) -> (Producer<'_, Ty, SIZE>, Consumer<'_, Ty, SIZE>)
+    {
+        let ptr = self as *mut ConstQueue<Ty, SIZE>;
+        (
+            Producer { queue: ptr, _marker: PhantomData },
+            Consumer { queue: ptr, _marker: PhantomData }
+        )
+    }
+}
+
+impl <Ty, const SIZE: usize> Default for ConstQueue<Ty, SIZE>
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl <Ty, const SIZE: usize> Drop for ConstQueue<Ty, SIZE>
+{
+    fn drop(&mut self)
+    {
+        // Only the `len` slots starting at `start` (with wraparound) are
+        // initialized; everything else is raw memory that must not be dropped.
+        let len = self.len.load(Ordering::Relaxed);
+        let mut idx = self.start.load(Ordering::Relaxed);
+        for _ in 0..len
+        {
+            unsafe { self.buff.get_mut()[idx].assume_init_drop(); }
+            idx = (idx + 1) % SIZE;
+        }
+    }
+}
+
+impl <Ty, const SIZE: usize> Queue for ConstQueue<Ty, SIZE>
+{
+    type Item = Ty;
+
+    fn push(&mut self, item: Ty) -> Result<(), QueueErr>
+    {
+        ConstQueue::push(self, item)
+    }
+
+    fn pop(&mut self) -> Result<Ty, QueueErr>
+    {
+        ConstQueue::pop(self)
+    }
+
+    fn peek(&self) -> Result<&Ty, QueueErr>
+    {
+        ConstQueue::peek(self)
+    }
+
+    fn len(&self) -> usize
+    {
+        ConstQueue::len(self)
+    }
+
+    fn is_empty(&self) -> bool
+    {
+        ConstQueue::is_empty(self)
+    }
+
+    fn is_full(&self) -> bool
+    {
+        ConstQueue::is_full(self)
+    }
+}
+
+/// The producing half of a [`ConstQueue::split`]. Owns the right to advance
+/// `end` and write slots.
+pub struct Producer<'a, Ty, const SIZE: usize>
+{
+    queue: *mut ConstQueue<Ty, SIZE>,
+    _marker: PhantomData<&'a mut ConstQueue<Ty, SIZE>>,
+}
+
+/// The consuming half of a [`ConstQueue::split`]. Owns the right to advance
+/// `start` and read slots.
+pub struct Consumer<'a, Ty, const SIZE: usize>
+{
+    queue: *mut ConstQueue<Ty, SIZE>,
+    _marker: PhantomData<&'a mut ConstQueue<Ty, SIZE>>,
+}
+
+// The producer only ever writes `end` and its own slot, the consumer only
+// `start` and its own slot; `start` and `end` themselves are the cross-thread
+// synchronizers, each written by exactly one side and read by the other with
+// Acquire/Release. Crucially, neither half ever forms a reference to the
+// whole queue — all access goes through the field pointers below — so the
+// two raw pointers never produce aliasing `&`/`&mut`.
+unsafe impl<Ty: Send, const SIZE: usize> Send for Producer<'_, Ty, SIZE> {}
+unsafe impl<Ty: Send, const SIZE: usize> Send for Consumer<'_, Ty, SIZE> {}
+
+impl<'a, Ty, const SIZE: usize> Producer<'a, Ty, SIZE>
+{
+    /// Enqueues an item. Returns Err(QueueErr::QueueFull) if the queue is full.
+    ///
+    /// Reads its own `end` with `Relaxed` (only this side ever writes it) and
+    /// the consumer's `start` with `Acquire` to test fullness, then publishes
+    /// the slot write by storing the advanced `end` with `Release`, so the
+    /// consumer is guaranteed to see the write before it sees the advance.
+    pub fn enqueue(&mut self, item: Ty) -> Result<(), QueueErr>
+    {
+        // A zero-capacity queue has no slot to wrap into at all; check this
+        // before computing `% SIZE` below rather than dividing by zero.
+        if SIZE == 0
+        {
+            return Err(QueueErr::QueueFull);
+        }
+
+        let q = self.queue;
+        let end_ref = unsafe { &*addr_of!((*q).end) };
+        let start_ref = unsafe { &*addr_of!((*q).start) };
+
+        let end = end_ref.load(Ordering::Relaxed);
+        let next = (end + 1) % SIZE;
+        if next == start_ref.load(Ordering::Acquire)
+        {
+            return Err(QueueErr::QueueFull);
+        }
+
+        let buff = unsafe { (*q).buff.get() };
+        unsafe { addr_of_mut!((*buff)[end]).write(MaybeUninit::new(item)); }
+        end_ref.store(next, Ordering::Release);
+        Ok(())
+    }
+}
+
+impl<'a, Ty, const SIZE: usize> Consumer<'a, Ty, SIZE>
+{
+    /// Dequeues the oldest item, or Err(QueueErr::Empty) if the queue is empty.
+    ///
+    /// Reads its own `start` with `Relaxed` (only this side ever writes it)
+    /// and the producer's `end` with `Acquire` to test emptiness — which is
+    /// what guarantees the slot write from [`Producer::enqueue`] is visible
+    /// before this reads it — then publishes the free slot by storing the
+    /// advanced `start` with `Release`.
+    pub fn dequeue(&mut self) -> Result<Ty, QueueErr>
+    {
+        let q = self.queue;
+        let start_ref = unsafe { &*addr_of!((*q).start) };
+        let end_ref = unsafe { &*addr_of!((*q).end) };
+
+        let start = start_ref.load(Ordering::Relaxed);
+        if start == end_ref.load(Ordering::Acquire)
+        {
+            return Err(QueueErr::Empty);
+        }
+
+        let buff = unsafe { (*q).buff.get() };
+        let result = unsafe { (*addr_of!((*buff)[start])).assume_init_read() };
+        start_ref.store((start + 1) % SIZE, Ordering::Release);
+        return Ok(result);
+    }
+
+    /// Returns a reference to the oldest item without removing it, or
+    /// Err(QueueErr::Empty) if the queue is empty.
+    pub fn peek(&self) -> Result<&Ty, QueueErr>
+    {
+        let q = self.queue;
+        let start_ref = unsafe { &*addr_of!((*q).start) };
+        let end_ref = unsafe { &*addr_of!((*q).end) };
+
+        let start = start_ref.load(Ordering::Relaxed);
+        if start == end_ref.load(Ordering::Acquire)
+        {
+            return Err(QueueErr::Empty);
+        }
+
+        let buff = unsafe { (*q).buff.get() };
+        return Ok(unsafe { (*addr_of!((*buff)[start])).assume_init_ref() });
+    }
+}
+
+/// Non-consuming iterator over shared references, returned by
+/// [`ConstQueue::iter`].
+pub struct Iter<'a, Ty, const SIZE: usize>
+{
+    buff: &'a [MaybeUninit<Ty>; SIZE],
+    idx: usize,
+    remaining: usize,
+}
+
+impl<'a, Ty, const SIZE: usize> Iterator for Iter<'a, Ty, SIZE>
+{
+    type Item = &'a Ty;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.remaining == 0
+        {
+            return None;
+        }
+
+        let i = self.idx;
+        self.idx = (self.idx + 1) % SIZE;
+        self.remaining -= 1;
+        return Some(unsafe { self.buff[i].assume_init_ref() });
     }
+}
+
+/// Non-consuming iterator over mutable references, returned by
+/// [`ConstQueue::iter_mut`].
+pub struct IterMut<'a, Ty, const SIZE: usize>
+{
+    buff: *mut MaybeUninit<Ty>,
+    idx: usize,
+    remaining: usize,
+    _marker: PhantomData<&'a mut Ty>,
+}
+
+impl<'a, Ty, const SIZE: usize> Iterator for IterMut<'a, Ty, SIZE>
+{
+    type Item = &'a mut Ty;
 
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.remaining == 0
+        {
+            return None;
+        }
+
+        let i = self.idx;
+        self.idx = (self.idx + 1) % SIZE;
+        self.remaining -= 1;
+        // Each index is visited at most once per `remaining`, so the mutable
+        // references we hand out never alias.
+        return Some(unsafe { &mut *(*self.buff.add(i)).as_mut_ptr() });
+    }
 }
 
-impl <Ty, const SIZE: usize> Iterator for ConstQueue<Ty, SIZE> 
+/// Draining iterator, returned by [`ConstQueue::drain`]. Empties the queue
+/// when dropped.
+pub struct Drain<'a, Ty, const SIZE: usize>
+{
+    queue: &'a mut ConstQueue<Ty, SIZE>,
+}
+
+impl<'a, Ty, const SIZE: usize> Iterator for Drain<'a, Ty, SIZE>
+{
+    type Item = Ty;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        return self.queue.pop().ok();
+    }
+}
+
+impl<'a, Ty, const SIZE: usize> Drop for Drain<'a, Ty, SIZE>
+{
+    fn drop(&mut self)
+    {
+        while self.queue.pop().is_ok() {}
+    }
+}
+
+impl <Ty, const SIZE: usize> Iterator for ConstQueue<Ty, SIZE>
 where Ty: Copy
 {
     type Item = Ty;
@@ -170,14 +556,12 @@ mod tests {
     #[test]
     pub fn push_fails_if_queue_full()
     {
-        /*
-            The queue will always leave one slote between end and start, 
-            if end is coming up from behind. 
-         */
+        /* The MaybeUninit-backed store holds a full SIZE elements. */
         let mut q = ConstQueue::<i32, 3>::new();
         assert!(q.push(1).is_ok());
         assert!(q.push(2).is_ok());
-        assert!(q.push(3).is_err());
+        assert!(q.push(3).is_ok());
+        assert!(q.push(4).is_err());
     }
 
     #[test]
@@ -221,7 +605,7 @@ mod tests {
     pub fn pop_fails_if_empty()
     {
         let mut q = ConstQueue::<i32, 4>::new();
-        assert!(q.pop().is_err());       
+        assert!(q.pop().is_err());
     }
 
     #[test]
@@ -239,4 +623,257 @@ mod tests {
         assert!(values == vec![10,20]);
     }
 
-}
\ No newline at end of file
+    #[test]
+    pub fn can_fill_every_slot()
+    {
+        let mut q = ConstQueue::<i32, 3>::new();
+        let _ = q.push(1);
+        let _ = q.push(2);
+        let _ = q.push(3);
+        assert!(q.pop().unwrap() == 1);
+        assert!(q.pop().unwrap() == 2);
+        assert!(q.pop().unwrap() == 3);
+    }
+
+    #[test]
+    pub fn drop_runs_for_remaining_items()
+    {
+        use std::rc::Rc;
+        let counter = Rc::new(());
+        {
+            let mut q = ConstQueue::<Rc<()>, 4>::new();
+            let _ = q.push(counter.clone());
+            let _ = q.push(counter.clone());
+            assert!(Rc::strong_count(&counter) == 3);
+        }
+        /* Dropping the queue must drop the two live clones. */
+        assert!(Rc::strong_count(&counter) == 1);
+    }
+
+    #[test]
+    pub fn overwrite_push_returns_none_with_spare_capacity()
+    {
+        let mut q = ConstQueue::<i32, 4>::new();
+        assert!(q.overwrite_push(1).is_none());
+        assert!(q.overwrite_push(2).is_none());
+    }
+
+    #[test]
+    pub fn overwrite_push_evicts_oldest_when_full()
+    {
+        let mut q = ConstQueue::<i32, 2>::new();
+        assert!(q.overwrite_push(1).is_none());
+        assert!(q.overwrite_push(2).is_none());
+        /* Full now; the next push evicts 1. */
+        assert!(q.overwrite_push(3) == Some(1));
+        assert!(q.pop().unwrap() == 2);
+        assert!(q.pop().unwrap() == 3);
+        assert!(q.pop().is_err());
+    }
+
+    #[test]
+    pub fn overwrite_push_drops_evicted_item_exactly_once()
+    {
+        use std::rc::Rc;
+        let evicted = Rc::new(());
+        let survivor = Rc::new(());
+        {
+            let mut q = ConstQueue::<Rc<()>, 2>::new();
+            assert!(q.overwrite_push(evicted.clone()).is_none());
+            assert!(q.overwrite_push(survivor.clone()).is_none());
+            /* Full now; overwrite_push must move the evicted item out of its
+               slot so its Drop runs here rather than being leaked or
+               double-dropped. */
+            let old = q.overwrite_push(survivor.clone()).unwrap();
+            assert!(Rc::strong_count(&evicted) == 2);
+            drop(old);
+            assert!(Rc::strong_count(&evicted) == 1);
+            assert!(Rc::strong_count(&survivor) == 3);
+        }
+        assert!(Rc::strong_count(&survivor) == 1);
+    }
+
+    #[test]
+    pub fn overwrite_push_on_zero_capacity_returns_the_item_unstored()
+    {
+        let mut q = ConstQueue::<i32, 0>::new();
+        assert!(q.overwrite_push(1) == Some(1));
+    }
+
+    #[test]
+    pub fn overwrite_push_wraps_around()
+    {
+        let mut q = ConstQueue::<i32, 3>::new();
+        for i in 0..10
+        {
+            let _ = q.overwrite_push(i);
+        }
+        /* The last three survive: 7, 8, 9. */
+        assert!(q.pop().unwrap() == 7);
+        assert!(q.pop().unwrap() == 8);
+        assert!(q.pop().unwrap() == 9);
+        assert!(q.pop().is_err());
+    }
+
+    #[test]
+    pub fn iter_yields_items_oldest_first()
+    {
+        let mut q = ConstQueue::<i32, 4>::new();
+        let _ = q.push(1);
+        let _ = q.push(2);
+        let _ = q.push(3);
+        let collected: Vec<i32> = q.iter().copied().collect();
+        assert!(collected == vec![1, 2, 3]);
+        /* iter() must not consume: the queue still holds all three items. */
+        assert!(q.len() == 3);
+    }
+
+    #[test]
+    pub fn iter_walks_across_wraparound()
+    {
+        let mut q = ConstQueue::<i32, 4>::new();
+        let _ = q.push(1);
+        let _ = q.push(2);
+        let _ = q.pop();
+        let _ = q.push(3);
+        let _ = q.push(4);
+        /* Slots 1..3 are taken, so 5 wraps around into slot 0. */
+        let _ = q.push(5);
+        let collected: Vec<i32> = q.iter().copied().collect();
+        assert!(collected == vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    pub fn iter_mut_edits_in_place()
+    {
+        let mut q = ConstQueue::<i32, 4>::new();
+        let _ = q.push(1);
+        let _ = q.push(2);
+        for v in q.iter_mut()
+        {
+            *v *= 10;
+        }
+        assert!(q.pop().unwrap() == 10);
+        assert!(q.pop().unwrap() == 20);
+    }
+
+    #[test]
+    pub fn drain_yields_and_empties()
+    {
+        let mut q = ConstQueue::<i32, 4>::new();
+        let _ = q.push(1);
+        let _ = q.push(2);
+        let drained: Vec<i32> = q.drain().collect();
+        assert!(drained == vec![1, 2]);
+        assert!(q.empty());
+    }
+
+    #[test]
+    pub fn dropping_drain_empties_remaining()
+    {
+        let mut q = ConstQueue::<i32, 4>::new();
+        let _ = q.push(1);
+        let _ = q.push(2);
+        {
+            let mut d = q.drain();
+            assert!(d.next() == Some(1));
+        }
+        assert!(q.empty());
+    }
+
+    #[test]
+    pub fn len_and_capacity_and_is_full_track_state()
+    {
+        let mut q = ConstQueue::<i32, 3>::new();
+        assert!(q.capacity() == 3);
+        assert!(q.len() == 0);
+        assert!(!q.is_full());
+        let _ = q.push(1);
+        let _ = q.push(2);
+        let _ = q.push(3);
+        assert!(q.len() == 3);
+        assert!(q.is_full());
+    }
+
+    #[test]
+    pub fn can_use_queue_through_trait()
+    {
+        use crate::Queue;
+
+        fn fill<Q: Queue<Item = i32>>(q: &mut Q)
+        {
+            let _ = q.push(1);
+            let _ = q.push(2);
+        }
+
+        let mut q = ConstQueue::<i32, 4>::new();
+        fill(&mut q);
+        assert!(q.len() == 2);
+        assert!(*q.peek().unwrap() == 1);
+        assert!(!q.is_full());
+    }
+
+    #[test]
+    pub fn queue_err_displays()
+    {
+        use crate::QueueErr;
+        assert!(format!("{}", QueueErr::QueueFull) == "queue is full");
+        assert!(format!("{}", QueueErr::Empty) == "queue is empty");
+    }
+
+    #[test]
+    pub fn split_producer_and_consumer_share_the_queue()
+    {
+        let mut q = ConstQueue::<i32, 4>::new();
+        let (mut p, mut c) = q.split();
+        assert!(p.enqueue(10).is_ok());
+        assert!(p.enqueue(20).is_ok());
+        assert!(*c.peek().unwrap() == 10);
+        assert!(c.dequeue().unwrap() == 10);
+        assert!(c.dequeue().unwrap() == 20);
+        assert!(c.dequeue().is_err());
+    }
+
+    #[test]
+    pub fn split_producer_and_consumer_cross_real_threads()
+    {
+        const N: i32 = 200_000;
+        let mut q = ConstQueue::<i32, 64>::new();
+        let (mut p, mut c) = q.split();
+
+        std::thread::scope(|scope| {
+            scope.spawn(move || {
+                let mut next = 0;
+                while next < N
+                {
+                    if p.enqueue(next).is_ok()
+                    {
+                        next += 1;
+                    }
+                }
+            });
+
+            scope.spawn(move || {
+                let mut expected = 0;
+                while expected < N
+                {
+                    if let Ok(item) = c.dequeue()
+                    {
+                        /* Crossing real threads must not reorder or duplicate items. */
+                        assert!(item == expected);
+                        expected += 1;
+                    }
+                }
+            });
+        });
+    }
+
+    #[test]
+    pub fn enqueue_fails_rather_than_panics_on_zero_capacity()
+    {
+        let mut q = ConstQueue::<i32, 0>::new();
+        let (mut p, _c) = q.split();
+        assert!(p.enqueue(1) == Err(crate::QueueErr::QueueFull));
+    }
+
+}
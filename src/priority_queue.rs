@@ -0,0 +1,241 @@
+use core::mem::MaybeUninit;
+
+use crate::QueueErr;
+
+/// A fixed-capacity priority queue backed by the same const-array storage as
+/// [`ConstQueue`](crate::ConstQueue), maintained as a binary max-heap over the
+/// occupied indices `0..len`. It offers ordered extraction alongside the FIFO
+/// queue: `pop` always yields the current maximum.
+pub struct ConstPriorityQueue<Ty: Ord, const SIZE: usize>
+{
+    buff: [MaybeUninit<Ty>; SIZE],
+    len: usize,
+}
+
+impl <Ty: Ord, const SIZE: usize> ConstPriorityQueue<Ty, SIZE>
+{
+    pub fn new() -> Self
+    {
+        Self
+        {
+            // Safe for any `Ty`: a `[MaybeUninit<Ty>; SIZE]` array needs no
+            // per-element initialization to be itself initialized, and which
+            // of the `SIZE` slots actually hold heap nodes is tracked by
+            // `len`, exactly as in `ConstQueue`.
+            buff: unsafe { MaybeUninit::uninit().assume_init() },
+            len: 0,
+        }
+    }
+
+    /// Yields true, if the queue is empty
+    pub fn empty(&self) -> bool
+    {
+        return self.is_empty();
+    }
+
+    /// Yields true, if the queue is empty
+    pub fn is_empty(&self) -> bool
+    {
+        return self.len == 0;
+    }
+
+    /// Returns the number of items currently in the queue.
+    pub fn len(&self) -> usize
+    {
+        return self.len;
+    }
+
+    /// Yields true when the queue holds SIZE items and no further push will
+    /// succeed.
+    pub fn is_full(&self) -> bool
+    {
+        return self.len == SIZE;
+    }
+
+    /// Returns the fixed capacity of the queue.
+    pub fn capacity(&self) -> usize
+    {
+        return SIZE;
+    }
+
+    /// Tries to push a given item into the queue, restoring the heap order by
+    /// sifting it up towards the root.
+    /// # Arguments
+    /// * `item`: The item to push
+    ///
+    /// Will Result Err(QueueErr::QueueFull) if the queue is full
+    pub fn push(&mut self, item: Ty) -> Result<(), QueueErr>
+    {
+        if self.len == SIZE
+        {
+            return Err(QueueErr::QueueFull);
+        }
+
+        let mut child = self.len;
+        self.buff[child] = MaybeUninit::new(item);
+        self.len += 1;
+
+        while child > 0
+        {
+            let parent = (child - 1) / 2;
+            let greater = unsafe {
+                self.buff[child].assume_init_ref() > self.buff[parent].assume_init_ref()
+            };
+            if !greater
+            {
+                break;
+            }
+            self.buff.swap(child, parent);
+            child = parent;
+        }
+
+        Ok(())
+    }
+
+    /// Returns an immutable reference to the current maximum in O(1), or
+    /// Err(QueueErr::Empty) if no element is in the queue.
+    pub fn peek(&self) -> Result<&Ty, QueueErr>
+    {
+        if self.len == 0
+        {
+            return Err(QueueErr::Empty);
+        }
+
+        return Ok(unsafe { self.buff[0].assume_init_ref() });
+    }
+
+    /// Removes the current maximum and returns it, restoring the heap order by
+    /// sifting the moved root back down.
+    /// Will return Err(QueueErr::Empty) if no item is in the queue.
+    pub fn pop(&mut self) -> Result<Ty, QueueErr>
+    {
+        if self.len == 0
+        {
+            return Err(QueueErr::Empty);
+        }
+
+        self.buff.swap(0, self.len - 1);
+        self.len -= 1;
+        let result = unsafe { self.buff[self.len].assume_init_read() };
+
+        let mut i = 0;
+        loop
+        {
+            let left = 2 * i + 1;
+            let right = 2 * i + 2;
+            let mut largest = i;
+            if left < self.len && unsafe {
+                self.buff[left].assume_init_ref() > self.buff[largest].assume_init_ref()
+            }
+            {
+                largest = left;
+            }
+            if right < self.len && unsafe {
+                self.buff[right].assume_init_ref() > self.buff[largest].assume_init_ref()
+            }
+            {
+                largest = right;
+            }
+            if largest == i
+            {
+                break;
+            }
+            self.buff.swap(i, largest);
+            i = largest;
+        }
+
+        return Ok(result);
+    }
+}
+
+impl <Ty: Ord, const SIZE: usize> Default for ConstPriorityQueue<Ty, SIZE>
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl <Ty: Ord, const SIZE: usize> Drop for ConstPriorityQueue<Ty, SIZE>
+{
+    fn drop(&mut self)
+    {
+        // Only the first `len` slots of the heap are initialized.
+        for i in 0..self.len
+        {
+            unsafe { self.buff[i].assume_init_drop(); }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::priority_queue::ConstPriorityQueue;
+
+    #[test]
+    pub fn can_push_value()
+    {
+        let mut q = ConstPriorityQueue::<i32, 4>::new();
+        assert!(q.push(1).is_ok());
+    }
+
+    #[test]
+    pub fn push_fails_if_queue_full()
+    {
+        let mut q = ConstPriorityQueue::<i32, 2>::new();
+        assert!(q.push(1).is_ok());
+        assert!(q.push(2).is_ok());
+        assert!(q.push(3).is_err());
+    }
+
+    #[test]
+    pub fn peek_yields_maximum()
+    {
+        let mut q = ConstPriorityQueue::<i32, 8>::new();
+        let _ = q.push(3);
+        let _ = q.push(7);
+        let _ = q.push(5);
+        assert!(*q.peek().unwrap() == 7);
+    }
+
+    #[test]
+    pub fn peek_fails_if_empty()
+    {
+        let q = ConstPriorityQueue::<i32, 4>::new();
+        assert!(q.peek().is_err());
+    }
+
+    #[test]
+    pub fn pops_in_descending_order()
+    {
+        let mut q = ConstPriorityQueue::<i32, 8>::new();
+        for v in [3, 1, 4, 1, 5, 9, 2]
+        {
+            let _ = q.push(v);
+        }
+        let mut popped = Vec::<i32>::new();
+        while let Ok(v) = q.pop()
+        {
+            popped.push(v);
+        }
+        assert!(popped == vec![9, 5, 4, 3, 2, 1, 1]);
+    }
+
+    #[test]
+    pub fn pop_fails_if_empty()
+    {
+        let mut q = ConstPriorityQueue::<i32, 4>::new();
+        assert!(q.pop().is_err());
+    }
+
+    #[test]
+    pub fn empty_tracks_state()
+    {
+        let mut q = ConstPriorityQueue::<i32, 4>::new();
+        assert!(q.empty());
+        let _ = q.push(1);
+        assert!(!q.empty());
+        let _ = q.pop();
+        assert!(q.empty());
+    }
+}
@@ -0,0 +1,31 @@
+use crate::QueueErr;
+
+/// A bounded FIFO queue abstraction, so generic code can be written against
+/// any fixed-capacity queue backend rather than a concrete type.
+pub trait Queue
+{
+    /// The element type stored in the queue.
+    type Item;
+
+    /// Tries to push an item, returning Err(QueueErr::QueueFull) when full.
+    fn push(&mut self, item: Self::Item) -> Result<(), QueueErr>;
+
+    /// Removes and returns the oldest item, or Err(QueueErr::Empty) when empty.
+    fn pop(&mut self) -> Result<Self::Item, QueueErr>;
+
+    /// Returns a reference to the oldest item, or Err(QueueErr::Empty) when
+    /// empty.
+    fn peek(&self) -> Result<&Self::Item, QueueErr>;
+
+    /// Returns the number of items currently in the queue.
+    fn len(&self) -> usize;
+
+    /// Yields true when the queue holds no items.
+    fn is_empty(&self) -> bool
+    {
+        self.len() == 0
+    }
+
+    /// Yields true when no further item can be pushed.
+    fn is_full(&self) -> bool;
+}